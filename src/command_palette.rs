@@ -0,0 +1,228 @@
+use gpui::{
+    actions, div, prelude::*, App, Context, Entity, EventEmitter, FocusHandle, Focusable,
+    KeyBinding, MouseButton, Render, SharedString, Subscription, Task, Window,
+};
+use gpui_component::{
+    h_flex,
+    input::{Input, InputEvent, InputState},
+    v_flex, ActiveTheme, StyledExt,
+};
+
+use fuzzy::{StringMatch, StringMatchCandidate};
+
+actions!(command_palette, [ToggleCommandPalette, SelectNext, SelectPrev, Dismiss]);
+
+pub fn init(cx: &mut App) {
+    cx.bind_keys([
+        KeyBinding::new("cmd-shift-p", ToggleCommandPalette, None),
+        KeyBinding::new("down", SelectNext, Some("CommandPalette")),
+        KeyBinding::new("up", SelectPrev, Some("CommandPalette")),
+        KeyBinding::new("escape", Dismiss, Some("CommandPalette")),
+    ]);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaletteCommand {
+    AddTodo,
+    ClearCompleted,
+    ToggleAllComplete,
+    FocusInput,
+    SwitchTheme,
+}
+
+impl PaletteCommand {
+    const ALL: &'static [(Self, &'static str)] = &[
+        (Self::AddTodo, "Add Todo"),
+        (Self::ClearCompleted, "Clear Completed"),
+        (Self::ToggleAllComplete, "Toggle All Complete"),
+        (Self::FocusInput, "Focus Input"),
+        (Self::SwitchTheme, "Switch Theme"),
+    ];
+
+    fn name(self) -> &'static str {
+        Self::ALL
+            .iter()
+            .find(|(command, _)| *command == self)
+            .map(|(_, name)| *name)
+            .unwrap_or_default()
+    }
+}
+
+pub struct CommandSelected(pub PaletteCommand);
+impl EventEmitter<CommandSelected> for CommandPalette {}
+
+pub struct Dismissed;
+impl EventEmitter<Dismissed> for CommandPalette {}
+
+pub struct CommandPalette {
+    query_state: Entity<InputState>,
+    matches: Vec<StringMatch>,
+    selected_ix: usize,
+    focus_handle: FocusHandle,
+    _match_task: Option<Task<()>>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl CommandPalette {
+    pub fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let query_state = cx.new(|cx| InputState::new(window, cx).placeholder("Type a command..."));
+
+        let query_subscription = cx.subscribe_in(
+            &query_state,
+            window,
+            |this, _, ev: &InputEvent, window, cx| match ev {
+                InputEvent::Change => this.update_matches(window, cx),
+                InputEvent::PressEnter { .. } => this.confirm(cx),
+                _ => {}
+            },
+        );
+
+        window.focus(&query_state.focus_handle(cx));
+
+        let mut this = Self {
+            query_state,
+            matches: Vec::new(),
+            selected_ix: 0,
+            focus_handle: cx.focus_handle(),
+            _match_task: None,
+            _subscriptions: vec![query_subscription],
+        };
+        this.update_matches(window, cx);
+        this
+    }
+
+    fn update_matches(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
+        let query = self.query_state.read(cx).value().to_string();
+        let candidates: Vec<StringMatchCandidate> = PaletteCommand::ALL
+            .iter()
+            .enumerate()
+            .map(|(ix, (_, name))| StringMatchCandidate::new(ix, name))
+            .collect();
+
+        self.selected_ix = 0;
+        self._match_task = Some(cx.spawn(async move |this, cx| {
+            let matches = if query.is_empty() {
+                candidates
+                    .iter()
+                    .map(|candidate| StringMatch {
+                        candidate_id: candidate.id,
+                        score: 0.,
+                        positions: Vec::new(),
+                        string: candidate.string.clone(),
+                    })
+                    .collect()
+            } else {
+                let executor = cx.background_executor().clone();
+                cx.background_spawn({
+                    let candidates = candidates.clone();
+                    let query = query.clone();
+                    async move {
+                        fuzzy::match_strings(
+                            &candidates,
+                            &query,
+                            false,
+                            100,
+                            &Default::default(),
+                            executor,
+                        )
+                        .await
+                    }
+                })
+                .await
+            };
+
+            this.update(cx, |this, cx| {
+                this.matches = matches;
+                cx.notify();
+            })
+            .ok();
+        }));
+    }
+
+    fn confirm(&mut self, cx: &mut Context<Self>) {
+        if let Some(m) = self.matches.get(self.selected_ix) {
+            let command = PaletteCommand::ALL[m.candidate_id].0;
+            cx.emit(CommandSelected(command));
+        }
+        cx.emit(Dismissed);
+    }
+
+    fn select_next(&mut self, cx: &mut Context<Self>) {
+        if !self.matches.is_empty() {
+            self.selected_ix = (self.selected_ix + 1) % self.matches.len();
+            cx.notify();
+        }
+    }
+
+    fn select_prev(&mut self, cx: &mut Context<Self>) {
+        if !self.matches.is_empty() {
+            self.selected_ix = (self.selected_ix + self.matches.len() - 1) % self.matches.len();
+            cx.notify();
+        }
+    }
+
+    fn select_and_confirm(&mut self, ix: usize, cx: &mut Context<Self>) {
+        self.selected_ix = ix;
+        self.confirm(cx);
+    }
+}
+
+impl Focusable for CommandPalette {
+    fn focus_handle(&self, _cx: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for CommandPalette {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        v_flex()
+            .track_focus(&self.focus_handle)
+            .key_context("CommandPalette")
+            .on_action(cx.listener(|this, _: &SelectNext, _, cx| this.select_next(cx)))
+            .on_action(cx.listener(|this, _: &SelectPrev, _, cx| this.select_prev(cx)))
+            .on_action(cx.listener(|this, _: &Dismiss, _, cx| cx.emit(Dismissed)))
+            .absolute()
+            .top_12()
+            .left_1_4()
+            .w_1_2()
+            .rounded_md()
+            .shadow_lg()
+            .border_1()
+            .border_color(cx.theme().border)
+            .bg(cx.theme().background)
+            .child(Input::new(&self.query_state).m_2())
+            .child(
+                v_flex()
+                    .gap_1()
+                    .px_2()
+                    .pb_2()
+                    .children(self.matches.iter().enumerate().map(|(ix, m)| {
+                        let name = PaletteCommand::ALL[m.candidate_id].0.name();
+                        h_flex()
+                            .px_2()
+                            .py_1()
+                            .rounded_sm()
+                            .when(ix == self.selected_ix, |s| s.bg(cx.theme().accent))
+                            .child(highlighted_label(name, &m.positions, cx))
+                            .on_mouse_down(
+                                MouseButton::Left,
+                                cx.listener(move |this, _, _, cx| {
+                                    this.select_and_confirm(ix, cx);
+                                }),
+                            )
+                    })),
+            )
+    }
+}
+
+fn highlighted_label(text: &str, positions: &[usize], cx: &App) -> impl IntoElement {
+    h_flex().children(text.chars().enumerate().map(|(ix, ch)| {
+        let span = div().child(SharedString::new(ch.to_string()));
+        if positions.contains(&ix) {
+            span.text_color(cx.theme().accent_foreground)
+                .font_weight(gpui::FontWeight::BOLD)
+        } else {
+            span
+        }
+    }))
+}
@@ -1,32 +1,132 @@
-use std::path::PathBuf;
+mod command_palette;
+mod persistence;
 
-use gpui::{Size, prelude::FluentBuilder, *};
+use std::{path::PathBuf, time::Duration};
+
+use gpui::{prelude::FluentBuilder, Size, *};
 use gpui_component::{
     button::*,
     checkbox::Checkbox,
     input::{Input, InputEvent, InputState},
-    scroll::ScrollableElement,
     *,
 };
 use gpui_component_assets::Assets;
 use uuid::Uuid;
 
+use fuzzy::{StringMatch, StringMatchCandidate};
+
+use command_palette::{
+    CommandPalette, CommandSelected, Dismissed, PaletteCommand, ToggleCommandPalette,
+};
+use persistence::TodoItemState;
+
 pub struct TodoItem {
     id: SharedString,
     title: SharedString,
     completed: bool,
+    match_positions: Vec<usize>,
+    edit_state: Option<Entity<InputState>>,
+    _edit_subscription: Option<Subscription>,
 }
 
 pub struct DeleteTodo;
 impl EventEmitter<DeleteTodo> for TodoItem {}
 
+pub struct TodoChanged;
+impl EventEmitter<TodoChanged> for TodoItem {}
+
 impl TodoItem {
     pub fn new(title: SharedString) -> Self {
         Self {
             id: Uuid::new_v4().to_string().into(),
             title,
             completed: false,
+            match_positions: Vec::new(),
+            edit_state: None,
+            _edit_subscription: None,
+        }
+    }
+
+    fn from_state(state: TodoItemState) -> Self {
+        Self {
+            id: state.id.into(),
+            title: state.title.into(),
+            completed: state.completed,
+            match_positions: Vec::new(),
+            edit_state: None,
+            _edit_subscription: None,
+        }
+    }
+
+    fn to_state(&self) -> TodoItemState {
+        TodoItemState {
+            id: self.id.to_string(),
+            title: self.title.to_string(),
+            completed: self.completed,
+        }
+    }
+
+    fn set_match_positions(&mut self, positions: Vec<usize>, cx: &mut Context<Self>) {
+        if self.match_positions != positions {
+            self.match_positions = positions;
+            cx.notify();
+        }
+    }
+
+    fn highlighted_title(&self) -> SharedString {
+        if self.match_positions.is_empty() {
+            return self.title.clone();
+        }
+
+        let mut markdown = String::with_capacity(self.title.len());
+        for (ix, ch) in self.title.chars().enumerate() {
+            if self.match_positions.contains(&ix) {
+                markdown.push_str("**");
+                markdown.push(ch);
+                markdown.push_str("**");
+            } else {
+                markdown.push(ch);
+            }
+        }
+        markdown.into()
+    }
+
+    pub fn begin_edit(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let edit_state = cx.new(|cx| {
+            let mut edit_state = InputState::new(window, cx)
+                .code_editor("markdown")
+                .multi_line(true)
+                .placeholder("Title...");
+            edit_state.set_value(self.title.clone(), window, cx);
+            edit_state.set_highlighter("markdown", cx);
+            edit_state
+        });
+
+        let subscription = cx.subscribe_in(
+            &edit_state,
+            window,
+            |this, _, ev: &InputEvent, window, cx| {
+                if let InputEvent::PressEnter { secondary } = ev {
+                    if !secondary {
+                        this.commit_edit(window, cx);
+                    }
+                }
+            },
+        );
+
+        window.focus(&edit_state.focus_handle(cx));
+        self.edit_state = Some(edit_state);
+        self._edit_subscription = Some(subscription);
+        cx.notify();
+    }
+
+    fn commit_edit(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(edit_state) = self.edit_state.take() {
+            self.title = edit_state.read(cx).value().clone();
         }
+        self._edit_subscription = None;
+        cx.emit(TodoChanged);
+        cx.notify();
     }
 }
 
@@ -48,6 +148,7 @@ impl Render for TodoItem {
                     .h_8()
                     .on_click(cx.listener(|this, &e, _, c| {
                         this.completed = e;
+                        c.emit(TodoChanged);
                         c.notify();
                     })),
             )
@@ -58,16 +159,21 @@ impl Render for TodoItem {
                     .m_1()
                     .flex_grow()
                     .overflow_hidden()
-                    .child(
-                        gpui_component::text::TextView::markdown(
-                            self.id.clone(),
-                            self.title.clone(),
-                            window,
-                            cx,
+                    .when_some(self.edit_state.clone(), |this, edit_state| {
+                        this.child(Input::new(&edit_state).size_full())
+                    })
+                    .when(self.edit_state.is_none(), |this| {
+                        this.child(
+                            gpui_component::text::TextView::markdown(
+                                self.id.clone(),
+                                self.highlighted_title(),
+                                window,
+                                cx,
+                            )
+                            .selectable(true),
                         )
-                        .selectable(true),
-                    )
-                    .when(self.completed, |s| s.line_through()),
+                        .when(self.completed, |s| s.line_through())
+                    }),
             )
             .child(
                 Button::new(SharedString::new(format!("delete-{}", self.id.clone())))
@@ -81,34 +187,318 @@ impl Render for TodoItem {
     }
 }
 
+const LIST_OVERDRAW: Pixels = px(200.);
+
+const SAVE_DEBOUNCE: Duration = Duration::from_millis(400);
+
+actions!(
+    todo_list,
+    [
+        ToggleCompleted,
+        DeleteSelected,
+        MoveUp,
+        MoveDown,
+        EditTitle,
+        SelectNext,
+        SelectPrev
+    ]
+);
+
+fn init_todo_list_keys(cx: &mut App) {
+    cx.bind_keys([
+        KeyBinding::new("space", ToggleCompleted, Some("TodoList")),
+        KeyBinding::new("backspace", DeleteSelected, Some("TodoList")),
+        KeyBinding::new("cmd-up", MoveUp, Some("TodoList")),
+        KeyBinding::new("cmd-down", MoveDown, Some("TodoList")),
+        KeyBinding::new("enter", EditTitle, Some("TodoList")),
+        KeyBinding::new("down", SelectNext, Some("TodoList")),
+        KeyBinding::new("up", SelectPrev, Some("TodoList")),
+    ]);
+}
+
 struct TodoList {
     items: Vec<Entity<TodoItem>>,
-    _selected_index: Option<IndexPath>,
+    list_state: ListState,
+    visible: Vec<usize>,
+    search_query: SharedString,
+    hide_completed: bool,
+    selected_index: Option<IndexPath>,
+    focus_handle: FocusHandle,
     _subscriptions: Vec<Subscription>,
+    _save_task: Option<Task<()>>,
+    _filter_task: Option<Task<()>>,
 }
 
 impl TodoList {
-    pub fn new() -> Self {
+    pub fn new(cx: &mut Context<Self>) -> Self {
+        let this = cx.entity().downgrade();
+        let list_state = ListState::new(0, ListAlignment::Top, LIST_OVERDRAW, move |ix, _, cx| {
+            this.upgrade()
+                .and_then(|this| {
+                    let this = this.read(cx);
+                    let item = this
+                        .visible
+                        .get(ix)
+                        .and_then(|&item_ix| this.items.get(item_ix))
+                        .cloned()?;
+                    let is_selected = this.selected_index.map(|s| s.row) == Some(ix);
+                    Some((item, is_selected))
+                })
+                .map(|(item, is_selected)| {
+                    div()
+                        .when(is_selected, |d| {
+                            d.border_2().border_color(gpui::blue()).rounded_sm()
+                        })
+                        .child(item)
+                        .into_any_element()
+                })
+                .unwrap_or_else(|| div().into_any_element())
+        });
+
         TodoList {
             items: Vec::new(),
-            _selected_index: None,
+            list_state,
+            visible: Vec::new(),
+            search_query: SharedString::default(),
+            hide_completed: false,
+            selected_index: None,
+            focus_handle: cx.focus_handle(),
             _subscriptions: Vec::new(),
+            _save_task: None,
+            _filter_task: None,
         }
     }
 
     pub fn add_item(&mut self, title: SharedString, cx: &mut Context<TodoList>) -> SharedString {
         let item = cx.new(|_| TodoItem::new(title.clone()));
         let id = item.read(cx).id.clone();
+        let item_ix = self.items.len();
+
+        self.push_item(item, cx);
+        self.refresh_visible(Some(item_ix), cx);
+        self.persist(cx);
+        id
+    }
+
+    fn restore_item(&mut self, state: TodoItemState, cx: &mut Context<TodoList>) {
+        let item = cx.new(|_| TodoItem::from_state(state));
+        self.push_item(item, cx);
+        self.refresh_visible(None, cx);
+    }
 
-        let subscription = cx.subscribe(&item, |this, e, _, c| {
-            this.items.retain(|i| *i != e);
+    fn push_item(&mut self, item: Entity<TodoItem>, cx: &mut Context<TodoList>) {
+        let delete_subscription = cx.subscribe(&item, |this, e, _: &DeleteTodo, c| {
+            let Some(ix) = this.items.iter().position(|i| *i == e) else {
+                return;
+            };
+            this.items.remove(ix);
+            this.refresh_visible(None, c);
+            this.persist(c);
             c.notify();
         });
+        let toggle_subscription = cx.subscribe(&item, |this, _, _: &TodoChanged, c| {
+            this.refresh_visible(None, c);
+            this.persist(c);
+        });
 
         self.items.push(item);
+        self._subscriptions.push(delete_subscription);
+        self._subscriptions.push(toggle_subscription);
+    }
 
-        self._subscriptions.push(subscription);
-        id
+    pub fn set_search_query(&mut self, query: SharedString, cx: &mut Context<Self>) {
+        self.search_query = query;
+        self.refresh_visible(None, cx);
+    }
+
+    pub fn set_hide_completed(&mut self, hide: bool, cx: &mut Context<Self>) {
+        self.hide_completed = hide;
+        self.refresh_visible(None, cx);
+    }
+
+    fn refresh_visible(&mut self, scroll_to_item: Option<usize>, cx: &mut Context<Self>) {
+        let query = self.search_query.to_string();
+        let hide_completed = self.hide_completed;
+        let candidates: Vec<StringMatchCandidate> = self
+            .items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| !(hide_completed && item.read(cx).completed))
+            .map(|(ix, item)| StringMatchCandidate::new(ix, &item.read(cx).title))
+            .collect();
+
+        self._filter_task = Some(cx.spawn(async move |this, cx| {
+            let matches = if query.is_empty() {
+                candidates
+                    .iter()
+                    .map(|candidate| StringMatch {
+                        candidate_id: candidate.id,
+                        score: 0.,
+                        positions: Vec::new(),
+                        string: candidate.string.clone(),
+                    })
+                    .collect::<Vec<_>>()
+            } else {
+                let executor = cx.background_executor().clone();
+                cx.background_spawn(async move {
+                    fuzzy::match_strings(
+                        &candidates,
+                        &query,
+                        false,
+                        100,
+                        &Default::default(),
+                        executor,
+                    )
+                    .await
+                })
+                .await
+            };
+
+            this.update(cx, |this, cx| {
+                let old_len = this.visible.len();
+                this.visible = matches.iter().map(|m| m.candidate_id).collect();
+
+                for m in &matches {
+                    if let Some(item) = this.items.get(m.candidate_id) {
+                        let positions = m.positions.clone();
+                        item.update(cx, |item, cx| item.set_match_positions(positions, cx));
+                    }
+                }
+
+                this.list_state.splice(0..old_len, this.visible.len());
+
+                if let Some(item_ix) = scroll_to_item {
+                    if let Some(visible_ix) = this.visible.iter().position(|&ix| ix == item_ix) {
+                        this.list_state.scroll_to(ListOffset {
+                            item_ix: visible_ix,
+                            offset_in_item: px(0.),
+                        });
+                    }
+                }
+
+                cx.notify();
+            })
+            .ok();
+        }));
+    }
+
+    fn persist(&mut self, cx: &mut Context<Self>) {
+        let snapshot: Vec<TodoItemState> = self
+            .items
+            .iter()
+            .map(|item| item.read(cx).to_state())
+            .collect();
+
+        self._save_task = Some(cx.spawn(async move |_, cx| {
+            cx.background_executor().timer(SAVE_DEBOUNCE).await;
+            let result = cx
+                .background_spawn(async move { persistence::save_todos(&snapshot) })
+                .await;
+            if let Err(err) = result {
+                println!("Failed to save todos: {err}");
+            }
+        }));
+    }
+
+    pub fn clear_completed(&mut self, cx: &mut Context<Self>) {
+        self.items.retain(|item| !item.read(cx).completed);
+        self.refresh_visible(None, cx);
+        self.persist(cx);
+    }
+
+    pub fn toggle_all_complete(&mut self, cx: &mut Context<Self>) {
+        let all_completed = self.items.iter().all(|item| item.read(cx).completed);
+        for item in self.items.clone() {
+            item.update(cx, |item, cx| {
+                item.completed = !all_completed;
+                cx.notify();
+            });
+        }
+        self.refresh_visible(None, cx);
+        self.persist(cx);
+    }
+
+    pub fn select_next(&mut self, cx: &mut Context<Self>) {
+        if self.visible.is_empty() {
+            return;
+        }
+        let next = self
+            .selected_index
+            .map(|s| (s.row + 1).min(self.visible.len() - 1))
+            .unwrap_or(0);
+        self.selected_index = Some(IndexPath::new(next));
+        cx.notify();
+    }
+
+    pub fn select_prev(&mut self, cx: &mut Context<Self>) {
+        if self.visible.is_empty() {
+            return;
+        }
+        let prev = self
+            .selected_index
+            .map(|s| s.row.saturating_sub(1))
+            .unwrap_or(0);
+        self.selected_index = Some(IndexPath::new(prev));
+        cx.notify();
+    }
+
+    fn selected_item(&self) -> Option<Entity<TodoItem>> {
+        let vix = self.selected_index?.row;
+        let item_ix = *self.visible.get(vix)?;
+        self.items.get(item_ix).cloned()
+    }
+
+    pub fn toggle_selected_completed(&mut self, cx: &mut Context<Self>) {
+        if let Some(item) = self.selected_item() {
+            item.update(cx, |item, cx| {
+                item.completed = !item.completed;
+                cx.emit(TodoChanged);
+                cx.notify();
+            });
+        }
+    }
+
+    pub fn delete_selected(&mut self, cx: &mut Context<Self>) {
+        if let Some(item) = self.selected_item() {
+            item.update(cx, |_, cx| cx.emit(DeleteTodo));
+        }
+    }
+
+    pub fn edit_selected_title(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(item) = self.selected_item() {
+            item.update(cx, |item, cx| item.begin_edit(window, cx));
+        }
+    }
+
+    fn swap_selected(&mut self, delta: isize, cx: &mut Context<Self>) {
+        let Some(vix) = self.selected_index.map(|s| s.row) else {
+            return;
+        };
+        let Some(other_vix) = vix
+            .checked_add_signed(delta)
+            .filter(|&v| v < self.visible.len())
+        else {
+            return;
+        };
+
+        self.items.swap(self.visible[vix], self.visible[other_vix]);
+        self.selected_index = Some(IndexPath::new(other_vix));
+        self.refresh_visible(None, cx);
+        self.persist(cx);
+    }
+
+    pub fn move_selected_up(&mut self, cx: &mut Context<Self>) {
+        self.swap_selected(-1, cx);
+    }
+
+    pub fn move_selected_down(&mut self, cx: &mut Context<Self>) {
+        self.swap_selected(1, cx);
+    }
+}
+
+impl Focusable for TodoList {
+    fn focus_handle(&self, _cx: &App) -> FocusHandle {
+        self.focus_handle.clone()
     }
 }
 
@@ -117,7 +507,14 @@ struct TodoApp {
 
     input_state: Entity<InputState>,
     editing_text: SharedString,
+    search_state: Entity<InputState>,
+    command_palette: Option<Entity<CommandPalette>>,
+    theme_cursor: usize,
+    theme_menu_open: bool,
+    current_theme_name: SharedString,
     _subscriptions: Vec<Subscription>,
+    _load_task: Task<()>,
+    _theme_save_task: Option<Task<()>>,
 }
 
 impl TodoApp {
@@ -159,13 +556,219 @@ impl TodoApp {
             }
         });
 
-        let todo_list = cx.new(|_| TodoList::new());
+        let search_state = cx.new(|cx| {
+            InputState::new(window, cx)
+                .searchable(true)
+                .placeholder("Search notes...")
+        });
+
+        let todo_list = cx.new(|cx| TodoList::new(cx));
+        window.focus(&todo_list.focus_handle(cx));
+
+        let search_subscription = cx.subscribe_in(&search_state, window, {
+            let todo_list = todo_list.clone();
+            let search_state = search_state.clone();
+            move |_, _, ev: &InputEvent, _, cx| {
+                if let InputEvent::Change = ev {
+                    let query = search_state.read(cx).value().clone();
+                    todo_list.update(cx, |todo_list, cx| {
+                        todo_list.set_search_query(query, cx);
+                    });
+                }
+            }
+        });
+
+        let load_task = cx.spawn({
+            let todo_list = todo_list.clone();
+            async move |_, cx| {
+                let loaded = cx
+                    .background_spawn(async { persistence::load_todos() })
+                    .await;
+                let items = match loaded {
+                    Ok(items) => items,
+                    Err(err) => {
+                        println!("Failed to load todos: {err}");
+                        return;
+                    }
+                };
+
+                todo_list
+                    .update(cx, |todo_list, cx| {
+                        for state in items {
+                            todo_list.restore_item(state, cx);
+                        }
+                    })
+                    .ok();
+            }
+        });
+
+        let theme_observer = cx.observe_global::<ThemeRegistry>(|_, cx| cx.notify());
+
+        let current_theme_name = persistence::load_theme_name()
+            .ok()
+            .flatten()
+            .unwrap_or_else(|| DEFAULT_THEME_NAME.to_string())
+            .into();
+
         Self {
             todo_list,
             input_state,
             editing_text: SharedString::new(""),
-            _subscriptions: vec![input_subscription],
+            search_state,
+            command_palette: None,
+            theme_cursor: 0,
+            theme_menu_open: false,
+            current_theme_name,
+            _subscriptions: vec![input_subscription, search_subscription, theme_observer],
+            _load_task: load_task,
+            _theme_save_task: None,
+        }
+    }
+
+    fn select_theme(&mut self, name: SharedString, cx: &mut Context<Self>) {
+        let Some(theme) = ThemeRegistry::global(cx).themes().get(&name).cloned() else {
+            return;
+        };
+        Theme::global_mut(cx).apply_config(&theme);
+        self.current_theme_name = name.clone();
+        self.theme_menu_open = false;
+
+        self._theme_save_task = Some(cx.spawn(async move |_, cx| {
+            cx.background_executor().timer(SAVE_DEBOUNCE).await;
+            let result = cx
+                .background_spawn(async move { persistence::save_theme_name(&name) })
+                .await;
+            if let Err(err) = result {
+                println!("Failed to save theme: {err}");
+            }
+        }));
+        cx.notify();
+    }
+
+    fn toggle_theme_menu(&mut self, cx: &mut Context<Self>) {
+        self.theme_menu_open = !self.theme_menu_open;
+        cx.notify();
+    }
+
+    fn render_theme_switcher(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let mut names: Vec<SharedString> =
+            ThemeRegistry::global(cx).themes().keys().cloned().collect();
+        names.sort();
+
+        h_flex()
+            .relative()
+            .child(
+                Button::new("theme-switcher")
+                    .label(self.current_theme_name.clone())
+                    .small()
+                    .ghost()
+                    .on_click(cx.listener(|this, _, _, cx| this.toggle_theme_menu(cx))),
+            )
+            .when(self.theme_menu_open, |this| {
+                this.child(
+                    v_flex()
+                        .absolute()
+                        .top_5()
+                        .right_0()
+                        .min_w_32()
+                        .rounded_md()
+                        .shadow_lg()
+                        .border_1()
+                        .border_color(cx.theme().border)
+                        .bg(cx.theme().background)
+                        .p_1()
+                        .gap_1()
+                        .children(names.into_iter().map(|name| {
+                            let selected = name == self.current_theme_name;
+                            h_flex()
+                                .px_2()
+                                .py_1()
+                                .rounded_sm()
+                                .when(selected, |s| s.bg(cx.theme().accent))
+                                .child(name.clone())
+                                .on_mouse_down(
+                                    MouseButton::Left,
+                                    cx.listener(move |this, _, _, cx| {
+                                        this.select_theme(name.clone(), cx);
+                                    }),
+                                )
+                        })),
+                )
+            })
+    }
+
+    fn toggle_command_palette(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.command_palette.take().is_some() {
+            cx.notify();
+            return;
+        }
+
+        let palette = cx.new(|cx| CommandPalette::new(window, cx));
+        let subscription = cx.subscribe_in(
+            &palette,
+            window,
+            |this, _, ev: &CommandSelected, window, cx| {
+                this.dispatch_command(ev.0, window, cx);
+            },
+        );
+        let dismiss_subscription = cx.subscribe(&palette, |this, _, _: &Dismissed, cx| {
+            this.command_palette = None;
+            cx.notify();
+        });
+
+        self._subscriptions.push(subscription);
+        self._subscriptions.push(dismiss_subscription);
+        self.command_palette = Some(palette);
+        cx.notify();
+    }
+
+    fn dispatch_command(
+        &mut self,
+        command: PaletteCommand,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.command_palette = None;
+
+        match command {
+            PaletteCommand::AddTodo => {
+                if !self.editing_text.is_empty() {
+                    self.todo_list.update(cx, |todo_list, cx| {
+                        todo_list.add_item(self.editing_text.clone(), cx);
+                    });
+                    self.editing_text = SharedString::new("");
+                    self.input_state.update(cx, |input_state, cx| {
+                        input_state.set_value("", window, cx);
+                    });
+                }
+                window.focus(&self.input_state.focus_handle(cx));
+            }
+            PaletteCommand::ClearCompleted => {
+                self.todo_list.update(cx, |todo_list, cx| {
+                    todo_list.clear_completed(cx);
+                });
+            }
+            PaletteCommand::ToggleAllComplete => {
+                self.todo_list.update(cx, |todo_list, cx| {
+                    todo_list.toggle_all_complete(cx);
+                });
+            }
+            PaletteCommand::FocusInput => {
+                window.focus(&self.input_state.focus_handle(cx));
+            }
+            PaletteCommand::SwitchTheme => {
+                let mut names: Vec<SharedString> =
+                    ThemeRegistry::global(cx).themes().keys().cloned().collect();
+                names.sort();
+
+                if !names.is_empty() {
+                    self.theme_cursor = (self.theme_cursor + 1) % names.len();
+                    self.select_theme(names[self.theme_cursor].clone(), cx);
+                }
+            }
         }
+
+        cx.notify();
     }
 }
 
@@ -173,13 +776,20 @@ impl Render for TodoApp {
     fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         v_flex()
             .size_full()
+            .on_action(cx.listener(|this, _: &ToggleCommandPalette, window, cx| {
+                this.toggle_command_palette(window, cx);
+            }))
+            .when_some(self.command_palette.clone(), |this, palette| {
+                this.child(palette)
+            })
             .child(
                 TitleBar::new().h_5().max_h_5().child(
                     h_flex()
                         .w_full()
                         .pr_2()
                         .justify_between()
-                        .child("Thoth Note"),
+                        .child("Thoth Note")
+                        .child(self.render_theme_switcher(cx)),
                 ),
             )
             .child(
@@ -210,7 +820,6 @@ impl Render for TodoApp {
                     )
                     .child(
                         v_flex()
-                            .overflow_y_scrollbar()
                             .h_full()
                             .max_h_full()
                             .relative()
@@ -219,21 +828,102 @@ impl Render for TodoApp {
                             .gap_1_2()
                             .m_2()
                             .p_2()
-                            .children(self.todo_list.read(cx).items.clone()),
+                            .child(
+                                h_flex()
+                                    .gap_2()
+                                    .items_center()
+                                    .child(Input::new(&self.search_state).flex_grow())
+                                    .child(
+                                        Checkbox::new("hide-completed")
+                                            .checked(self.todo_list.read(cx).hide_completed)
+                                            .on_click(cx.listener(|this, &hide, _, cx| {
+                                                this.todo_list.update(cx, |todo_list, cx| {
+                                                    todo_list.set_hide_completed(hide, cx);
+                                                });
+                                            })),
+                                    )
+                                    .child("Hide completed"),
+                            )
+                            .child(
+                                div()
+                                    .size_full()
+                                    .track_focus(&self.todo_list.focus_handle(cx))
+                                    .key_context("TodoList")
+                                    .on_action(cx.listener(|this, _: &SelectNext, _, cx| {
+                                        this.todo_list.update(cx, |todo_list, cx| {
+                                            todo_list.select_next(cx);
+                                        });
+                                    }))
+                                    .on_action(cx.listener(|this, _: &SelectPrev, _, cx| {
+                                        this.todo_list.update(cx, |todo_list, cx| {
+                                            todo_list.select_prev(cx);
+                                        });
+                                    }))
+                                    .on_action(cx.listener(|this, _: &ToggleCompleted, _, cx| {
+                                        this.todo_list.update(cx, |todo_list, cx| {
+                                            todo_list.toggle_selected_completed(cx);
+                                        });
+                                    }))
+                                    .on_action(cx.listener(|this, _: &DeleteSelected, _, cx| {
+                                        this.todo_list.update(cx, |todo_list, cx| {
+                                            todo_list.delete_selected(cx);
+                                        });
+                                    }))
+                                    .on_action(cx.listener(|this, _: &MoveUp, _, cx| {
+                                        this.todo_list.update(cx, |todo_list, cx| {
+                                            todo_list.move_selected_up(cx);
+                                        });
+                                    }))
+                                    .on_action(cx.listener(|this, _: &MoveDown, _, cx| {
+                                        this.todo_list.update(cx, |todo_list, cx| {
+                                            todo_list.move_selected_down(cx);
+                                        });
+                                    }))
+                                    .on_action(cx.listener(|this, _: &EditTitle, window, cx| {
+                                        this.todo_list.update(cx, |todo_list, cx| {
+                                            todo_list.edit_selected_title(window, cx);
+                                        });
+                                    }))
+                                    .child(
+                                        list(self.todo_list.read(cx).list_state.clone())
+                                            .size_full()
+                                            .on_scroll(cx.listener(
+                                                |_, _: &ListScrollEvent, _, cx| {
+                                                    cx.notify();
+                                                },
+                                            )),
+                                    ),
+                            ),
                     ),
             )
     }
 }
 
+const DEFAULT_THEME_NAME: &str = "Catppuccin Mocha";
+
+fn apply_saved_theme(cx: &mut App) {
+    let name = persistence::load_theme_name()
+        .ok()
+        .flatten()
+        .unwrap_or_else(|| DEFAULT_THEME_NAME.to_string());
+
+    let themes = ThemeRegistry::global(cx).themes();
+    let theme = themes
+        .get(&SharedString::from(name))
+        .or_else(|| themes.get(&SharedString::from(DEFAULT_THEME_NAME)))
+        .cloned();
+
+    if let Some(theme) = theme {
+        Theme::global_mut(cx).apply_config(&theme);
+    }
+}
+
 fn init(cx: &mut App) {
+    command_palette::init(cx);
+    init_todo_list_keys(cx);
+
     if let Err(_err) = ThemeRegistry::watch_dir(PathBuf::from("./themes"), cx, move |cx| {
-        if let Some(theme) = ThemeRegistry::global(cx)
-            .themes()
-            .get(&SharedString::from("Catppuccin Mocha"))
-            .cloned()
-        {
-            Theme::global_mut(cx).apply_config(&theme);
-        }
+        apply_saved_theme(cx)
     }) {
         println!("Failed to load theme")
     }
@@ -0,0 +1,67 @@
+use std::{collections::HashMap, fs, path::PathBuf, sync::Mutex};
+
+use serde::{Deserialize, Serialize};
+
+const TODOS_KEY: &str = "todos.v1";
+
+const THEME_KEY: &str = "theme.v1";
+
+/// Guards the read-modify-write of `kvp.json` so two debounced saves (e.g. a
+/// todo edit and a theme switch) landing in the same window can't clobber
+/// each other's key.
+static STORE_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TodoItemState {
+    pub id: String,
+    pub title: String,
+    pub completed: bool,
+}
+
+fn store_path() -> PathBuf {
+    let dir = dirs::data_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("thoth-note");
+    let _ = fs::create_dir_all(&dir);
+    dir.join("kvp.json")
+}
+
+fn read_kvp(key: &str) -> anyhow::Result<Option<String>> {
+    let Ok(contents) = fs::read_to_string(store_path()) else {
+        return Ok(None);
+    };
+    let map: HashMap<String, String> = serde_json::from_str(&contents)?;
+    Ok(map.get(key).cloned())
+}
+
+fn write_kvp(key: &str, value: String) -> anyhow::Result<()> {
+    let _guard = STORE_LOCK.lock().unwrap();
+
+    let path = store_path();
+    let mut map: HashMap<String, String> = fs::read_to_string(&path)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default();
+    map.insert(key.to_string(), value);
+    fs::write(path, serde_json::to_string(&map)?)?;
+    Ok(())
+}
+
+pub fn load_todos() -> anyhow::Result<Vec<TodoItemState>> {
+    match read_kvp(TODOS_KEY)? {
+        Some(json) => Ok(serde_json::from_str(&json)?),
+        None => Ok(Vec::new()),
+    }
+}
+
+pub fn save_todos(items: &[TodoItemState]) -> anyhow::Result<()> {
+    write_kvp(TODOS_KEY, serde_json::to_string(items)?)
+}
+
+pub fn load_theme_name() -> anyhow::Result<Option<String>> {
+    read_kvp(THEME_KEY)
+}
+
+pub fn save_theme_name(name: &str) -> anyhow::Result<()> {
+    write_kvp(THEME_KEY, name.to_string())
+}